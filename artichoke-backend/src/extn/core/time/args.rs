@@ -10,6 +10,7 @@ pub struct Args {
     pub minute: u8,
     pub second: u8,
     pub nanoseconds: u32,
+    pub utc_offset: Option<i32>,
 }
 
 impl Default for Args {
@@ -22,10 +23,35 @@ impl Default for Args {
             minute: 0,
             second: 0,
             nanoseconds: 0,
+            utc_offset: None,
         }
     }
 }
 
+/// Parses a string timezone argument of the form `(+|-)HH:MM`, or the special
+/// `UTC`/`Z` zones, into an offset in seconds east of UTC.
+///
+/// Returns `None` when the string is malformed.
+fn parse_utc_offset(bytes: &[u8]) -> Option<i32> {
+    if bytes.eq_ignore_ascii_case(b"UTC") || bytes.eq_ignore_ascii_case(b"Z") {
+        return Some(0);
+    }
+
+    // Expect exactly `(+|-)HH:MM`.
+    if bytes.len() != 6 || bytes[3] != b':' {
+        return None;
+    }
+    let sign = match bytes[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digit = |byte: u8| (byte as char).to_digit(10).map(|d| d as i32);
+    let hours = digit(bytes[1])? * 10 + digit(bytes[2])?;
+    let minutes = digit(bytes[4])? * 10 + digit(bytes[5])?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
 impl TryConvertMut<&mut [Value], Args> for Artichoke {
     type Error = Error;
 
@@ -54,6 +80,11 @@ impl TryConvertMut<&mut [Value], Args> for Artichoke {
 
         let mut result = Args::default();
 
+        // Tracks whether the seconds argument carried a fractional part. MRI
+        // rejects a fractional second combined with an explicit sub-second
+        // (micros) argument, so index 6 consults this flag.
+        let mut second_is_fractional = false;
+
         for (i, &arg) in args.iter().enumerate() {
             match i {
                 0 => {
@@ -63,15 +94,30 @@ impl TryConvertMut<&mut [Value], Args> for Artichoke {
                     result.year = i32::try_from(arg).map_err(|_| ArgumentError::with_message("year out of range"))?;
                 }
                 1 => {
-                    // TODO: This should support 3 letter month names
-                    // as per the docs. https://ruby-doc.org/3.1.2/Time.html#method-c-new
-                    let arg = to_int(self, arg)?;
-                    let arg: i64 = arg.try_convert_into::<Option<i64>>(self)?.unwrap();
-
-                    result.month = match u8::try_from(arg) {
-                        Ok(month @ 1..=12) => Ok(month),
-                        _ => Err(ArgumentError::with_message("mon out of range")),
-                    }?;
+                    // The month argument accepts a three letter English month
+                    // name in addition to an integer, per the Ruby docs.
+                    // https://ruby-doc.org/3.1.2/Time.html#method-c-new
+                    if let Ruby::String = arg.ruby_type() {
+                        let name: &[u8] = self.try_convert_mut(arg)?;
+                        const MONTHS: [&[u8]; 12] = [
+                            b"jan", b"feb", b"mar", b"apr", b"may", b"jun", b"jul", b"aug", b"sep", b"oct", b"nov",
+                            b"dec",
+                        ];
+                        let month = MONTHS.iter().position(|candidate| candidate.eq_ignore_ascii_case(name));
+                        result.month = match month {
+                            #[allow(clippy::cast_possible_truncation)] // position is in `0..12`
+                            Some(index) => index as u8 + 1,
+                            None => return Err(ArgumentError::with_message("mon out of range").into()),
+                        };
+                    } else {
+                        let arg = to_int(self, arg)?;
+                        let arg: i64 = arg.try_convert_into::<Option<i64>>(self)?.unwrap();
+
+                        result.month = match u8::try_from(arg) {
+                            Ok(month @ 1..=12) => Ok(month),
+                            _ => Err(ArgumentError::with_message("mon out of range")),
+                        }?;
+                    }
                 }
                 2 => {
                     let arg = to_int(self, arg)?;
@@ -101,21 +147,56 @@ impl TryConvertMut<&mut [Value], Args> for Artichoke {
                     }?;
                 }
                 5 => {
-                    // TODO: This should support f64 seconds and drop
-                    // the remainder into micros.
+                    // A `Float` seconds argument is split into a whole second
+                    // and sub-second nanoseconds.
+                    //
                     // ```irb
                     // 3.1.2 > Time.utc(1, 2, 3, 4, 5, 6.1)
                     // => 0001-02-03 04:05:06 56294995342131/562949953421312 UTC
                     // ```
-                    let arg = to_int(self, arg)?;
-                    let arg: i64 = arg.try_convert_into::<Option<i64>>(self)?.unwrap();
-
-                    result.second = match u8::try_from(arg) {
-                        Ok(second @ 0..=59) => Ok(second),
-                        _ => Err(ArgumentError::with_message("sec out of range")),
-                    }?;
+                    if let Ruby::Float = arg.ruby_type() {
+                        let seconds = arg.try_convert_into::<f64>(self)?;
+                        // Negative (and non-finite) floats are rejected with the
+                        // same message as the integer path.
+                        if !seconds.is_finite() || seconds.is_sign_negative() {
+                            return Err(ArgumentError::with_message("sec out of range").into());
+                        }
+
+                        let whole = seconds.floor();
+                        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // non-negative and finite
+                        let mut second = whole as u64;
+                        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // fraction in `0.0..1.0`
+                        let mut nanoseconds = ((seconds - whole) * 1_000_000_000.0).round() as u32;
+                        // Rounding can land exactly on one second; carry it into
+                        // the seconds field rather than overflowing nanos.
+                        if nanoseconds >= 1_000_000_000 {
+                            nanoseconds -= 1_000_000_000;
+                            second += 1;
+                        }
+
+                        result.second = match u8::try_from(second) {
+                            Ok(second @ 0..=59) => Ok(second),
+                            _ => Err(ArgumentError::with_message("sec out of range")),
+                        }?;
+                        result.nanoseconds = nanoseconds;
+                        second_is_fractional = true;
+                    } else {
+                        let arg = to_int(self, arg)?;
+                        let arg: i64 = arg.try_convert_into::<Option<i64>>(self)?.unwrap();
+
+                        result.second = match u8::try_from(arg) {
+                            Ok(second @ 0..=59) => Ok(second),
+                            _ => Err(ArgumentError::with_message("sec out of range")),
+                        }?;
+                    }
                 }
                 6 => {
+                    // MRI raises when a fractional seconds value is combined
+                    // with an explicit sub-second argument.
+                    if second_is_fractional {
+                        return Err(ArgumentError::with_message("subsecx out of range").into());
+                    }
+
                     let arg = to_int(self, arg)?;
                     let arg: i64 = arg.try_convert_into::<Option<i64>>(self)?.unwrap();
 
@@ -128,13 +209,33 @@ impl TryConvertMut<&mut [Value], Args> for Artichoke {
                     }?;
                 }
                 7 => {
-                    // NOOP
-                    // The 8th parameter can be anything, even an error
+                    // The final argument is an optional UTC offset, either an
+                    // integer number of seconds east of UTC or a string like
+                    // `"+09:00"`, `"-05:00"`, or `"UTC"`. Any other type is
+                    // ignored, matching the previous NOOP behavior.
                     //
                     // ```irb
-                    // Time.utc(2022, 1, 1, 0, 0, 0, 0, StandardError)
-                    // => 2022-01-01 00:00:00 UTC
+                    // Time.new(2022, 1, 1, 0, 0, 0, "+09:00")
+                    // => 2022-01-01 00:00:00 +0900
                     // ```
+                    match arg.ruby_type() {
+                        Ruby::Fixnum => {
+                            let arg = to_int(self, arg)?;
+                            let arg: i64 = arg.try_convert_into::<Option<i64>>(self)?.unwrap();
+                            result.utc_offset = match i32::try_from(arg) {
+                                Ok(offset @ -86_399..=86_399) => Some(offset),
+                                _ => return Err(ArgumentError::with_message("utc_offset out of range").into()),
+                            };
+                        }
+                        Ruby::String => {
+                            let zone: &[u8] = self.try_convert_mut(arg)?;
+                            match parse_utc_offset(zone) {
+                                Some(offset) => result.utc_offset = Some(offset),
+                                None => return Err(ArgumentError::with_message("can't parse offset").into()),
+                            }
+                        }
+                        _ => {}
+                    }
                 }
                 _ => {
                     // The 10 argument variant truncates, and the max length
@@ -335,7 +436,119 @@ mod tests {
     }
 
     #[test]
-    fn fractional_seconds_return_nanos() {}
+    fn fractional_seconds_return_nanos() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[2022, 1, 1, 0, 0, 6.1]").unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Args = interp.try_convert_mut(ary_args.as_mut_slice()).unwrap();
+        assert_eq!(6, result.second);
+        assert_eq!(100_000_000, result.nanoseconds);
+    }
+
+    #[test]
+    fn fractional_seconds_reject_negative() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[2022, 1, 1, 0, 0, -0.5]").unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Result<Args, Error> = interp.try_convert_mut(ary_args.as_mut_slice());
+        let error = result.unwrap_err();
+        assert_eq!(error.message().as_bstr(), b"sec out of range".as_bstr());
+    }
+
+    #[test]
+    fn fractional_seconds_conflict_with_micros() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[2022, 1, 1, 0, 0, 6.1, 7]").unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Result<Args, Error> = interp.try_convert_mut(ary_args.as_mut_slice());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn month_name_is_accepted() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(br#"[2022, "feb", 3]"#).unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Args = interp.try_convert_mut(ary_args.as_mut_slice()).unwrap();
+        assert_eq!(2, result.month);
+    }
+
+    #[test]
+    fn month_name_is_case_insensitive() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(br#"[2022, "DEC", 3]"#).unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Args = interp.try_convert_mut(ary_args.as_mut_slice()).unwrap();
+        assert_eq!(12, result.month);
+    }
+
+    #[test]
+    fn unknown_month_name_is_out_of_range() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(br#"[2022, "xyz", 3]"#).unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Result<Args, Error> = interp.try_convert_mut(ary_args.as_mut_slice());
+        let error = result.unwrap_err();
+        assert_eq!(error.message().as_bstr(), b"mon out of range".as_bstr());
+    }
+
+    #[test]
+    fn string_utc_offset_is_parsed() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(br#"[2022, 2, 3, 4, 5, 6, 7, "+09:00"]"#).unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Args = interp.try_convert_mut(ary_args.as_mut_slice()).unwrap();
+        assert_eq!(Some(9 * 3600), result.utc_offset);
+    }
+
+    #[test]
+    fn integer_utc_offset_is_parsed() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[2022, 2, 3, 4, 5, 6, 7, -18_000]").unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Args = interp.try_convert_mut(ary_args.as_mut_slice()).unwrap();
+        assert_eq!(Some(-18_000), result.utc_offset);
+    }
+
+    #[test]
+    fn utc_string_is_zero_offset() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(br#"[2022, 2, 3, 4, 5, 6, 7, "UTC"]"#).unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Args = interp.try_convert_mut(ary_args.as_mut_slice()).unwrap();
+        assert_eq!(Some(0), result.utc_offset);
+    }
+
+    #[test]
+    fn malformed_utc_offset_string_errors() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(br#"[2022, 2, 3, 4, 5, 6, 7, "not a zone"]"#).unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Result<Args, Error> = interp.try_convert_mut(ary_args.as_mut_slice());
+        let error = result.unwrap_err();
+        assert_eq!(error.message().as_bstr(), b"can't parse offset".as_bstr());
+    }
+
+    #[test]
+    fn integer_utc_offset_out_of_range_errors() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[2022, 2, 3, 4, 5, 6, 7, 100_000]").unwrap();
+        let mut ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result: Result<Args, Error> = interp.try_convert_mut(ary_args.as_mut_slice());
+        let error = result.unwrap_err();
+        assert_eq!(error.message().as_bstr(), b"utc_offset out of range".as_bstr());
+    }
 
     #[test]
     fn nine_args_not_supported() {
@@ -0,0 +1,298 @@
+//! A compact, length-prefixed binary cache format for the set of required
+//! feature paths (and optionally their source bytes).
+//!
+//! Persisting and reloading this set lets repeated interpreter boots skip
+//! re-walking the filesystem. Integers are stored using QUIC-style
+//! variable-length integers: the top two bits of the first byte select the
+//! total encoded length and the remaining bits hold the value big-endian.
+//!
+//! | Prefix | Bytes | Value bits |
+//! | ------ | ----- | ---------- |
+//! | `00`   | 1     | 6          |
+//! | `01`   | 2     | 14         |
+//! | `10`   | 4     | 30         |
+//! | `11`   | 8     | 62         |
+//!
+//! The on-disk layout is a 4-byte [magic](MAGIC), a 1-byte [version](VERSION),
+//! a varint entry count, then for each entry a `vvec` (varint length prefix
+//! followed by that many raw bytes) for the feature path and a `vvec` for its
+//! contents.
+
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Magic bytes identifying a feature cache blob.
+pub const MAGIC: [u8; 4] = *b"MZFL";
+
+/// Version of the cache format emitted by [`encode`].
+pub const VERSION: u8 = 1;
+
+/// Error raised while decoding a cache blob.
+///
+/// The decoder never panics; a read that runs past the end of the buffer, or a
+/// varint that declares more bytes than remain, surfaces here instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// A read required more bytes than remain in the buffer.
+    UnexpectedEof,
+    /// The blob did not begin with the expected [`MAGIC`] bytes.
+    BadMagic,
+    /// The blob declared a version this decoder does not understand.
+    UnsupportedVersion(u8),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEof => f.write_str("unexpected end of feature cache"),
+            DecodeError::BadMagic => f.write_str("feature cache has an invalid magic prefix"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported feature cache version: {version}")
+            }
+        }
+    }
+}
+
+impl error::Error for DecodeError {}
+
+impl From<DecodeError> for io::Error {
+    fn from(err: DecodeError) -> Self {
+        let kind = match err {
+            DecodeError::UnexpectedEof => io::ErrorKind::UnexpectedEof,
+            DecodeError::BadMagic | DecodeError::UnsupportedVersion(_) => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+/// An append-only writer that emits the cache encoding into an owned buffer.
+#[derive(Debug, Default, Clone)]
+pub struct Encoder {
+    buf: Vec<u8>,
+}
+
+impl Encoder {
+    /// Construct an empty encoder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { buf: Vec::new() }
+    }
+
+    /// Append a QUIC-style variable-length integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not fit in 62 bits, which cannot occur for the
+    /// lengths and counts emitted by this format.
+    pub fn encode_varint(&mut self, value: u64) {
+        if value < (1 << 6) {
+            #[allow(clippy::cast_possible_truncation)] // value < 64
+            self.buf.push(value as u8);
+        } else if value < (1 << 14) {
+            #[allow(clippy::cast_possible_truncation)] // value < 2^14
+            let tagged = value as u16 | 0x4000;
+            self.buf.extend_from_slice(&tagged.to_be_bytes());
+        } else if value < (1 << 30) {
+            #[allow(clippy::cast_possible_truncation)] // value < 2^30
+            let tagged = value as u32 | 0x8000_0000;
+            self.buf.extend_from_slice(&tagged.to_be_bytes());
+        } else if value < (1 << 62) {
+            let tagged = value | 0xC000_0000_0000_0000;
+            self.buf.extend_from_slice(&tagged.to_be_bytes());
+        } else {
+            panic!("varint value {value} exceeds 62 bits");
+        }
+    }
+
+    /// Append a varint length prefix followed by `bytes`.
+    pub fn encode_vvec(&mut self, bytes: &[u8]) {
+        self.encode_varint(bytes.len() as u64);
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Consume the encoder, returning the accumulated bytes.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// A read cursor over a borrowed cache blob.
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Construct a decoder positioned at the start of `bytes`.
+    #[must_use]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    /// Return the next `n` bytes, advancing the cursor, or
+    /// [`DecodeError::UnexpectedEof`] when fewer than `n` bytes remain.
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self.pos.checked_add(n).ok_or(DecodeError::UnexpectedEof)?;
+        let slice = self.bytes.get(self.pos..end).ok_or(DecodeError::UnexpectedEof)?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    /// Decode a QUIC-style variable-length integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError::UnexpectedEof`] when the declared length of the
+    /// varint exceeds the bytes remaining in the buffer.
+    pub fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        let &first = self.bytes.get(self.pos).ok_or(DecodeError::UnexpectedEof)?;
+        let len = 1usize << (first >> 6);
+        let slice = self.take(len)?;
+        // Mask off the two length-selector bits of the first byte.
+        let mut value = u64::from(slice[0] & 0x3f);
+        for &byte in &slice[1..] {
+            value = (value << 8) | u64::from(byte);
+        }
+        Ok(value)
+    }
+
+    /// Decode a varint length prefix and return the following raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError::UnexpectedEof`] when the length prefix claims
+    /// more bytes than remain.
+    pub fn read_vvec(&mut self) -> Result<&'a [u8], DecodeError> {
+        let len = self.read_varint()?;
+        let len = usize::try_from(len).map_err(|_| DecodeError::UnexpectedEof)?;
+        self.take(len)
+    }
+
+    /// Return `true` when the cursor has consumed the entire buffer.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+}
+
+/// Encode a list of `(path, contents)` feature entries into a cache blob.
+#[must_use]
+pub fn encode(entries: &[(Vec<u8>, Vec<u8>)]) -> Vec<u8> {
+    let mut encoder = Encoder::new();
+    encoder.buf.extend_from_slice(&MAGIC);
+    encoder.buf.push(VERSION);
+    encoder.encode_varint(entries.len() as u64);
+    for (path, contents) in entries {
+        encoder.encode_vvec(path);
+        encoder.encode_vvec(contents);
+    }
+    encoder.into_inner()
+}
+
+/// Decode a cache blob into its list of `(path, contents)` feature entries.
+///
+/// # Errors
+///
+/// Returns a [`DecodeError`] when the magic or version are wrong, or when any
+/// length-prefixed read runs past the end of the buffer.
+pub fn decode(bytes: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>, DecodeError> {
+    let mut decoder = Decoder::new(bytes);
+    if decoder.take(MAGIC.len())? != MAGIC {
+        return Err(DecodeError::BadMagic);
+    }
+    let version = decoder.take(1)?[0];
+    if version != VERSION {
+        return Err(DecodeError::UnsupportedVersion(version));
+    }
+    let count = decoder.read_varint()?;
+    let count = usize::try_from(count).map_err(|_| DecodeError::UnexpectedEof)?;
+    let mut entries = Vec::with_capacity(count.min(bytes.len()));
+    for _ in 0..count {
+        let path = decoder.read_vvec()?.to_vec();
+        let contents = decoder.read_vvec()?.to_vec();
+        entries.push((path, contents));
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varint_round_trips_across_widths() {
+        for value in [0, 63, 64, 16_383, 16_384, 1 << 29, 1 << 30, 1 << 61] {
+            let mut encoder = Encoder::new();
+            encoder.encode_varint(value);
+            let encoded = encoder.into_inner();
+            let mut decoder = Decoder::new(&encoded);
+            assert_eq!(decoder.read_varint().unwrap(), value);
+            assert!(decoder.is_empty());
+        }
+    }
+
+    #[test]
+    fn varint_uses_minimal_width() {
+        let mut encoder = Encoder::new();
+        encoder.encode_varint(63);
+        assert_eq!(encoder.into_inner().len(), 1);
+
+        let mut encoder = Encoder::new();
+        encoder.encode_varint(64);
+        assert_eq!(encoder.into_inner().len(), 2);
+    }
+
+    #[test]
+    fn vvec_round_trips() {
+        let mut encoder = Encoder::new();
+        encoder.encode_vvec(b"lib/set.rb");
+        let encoded = encoder.into_inner();
+        let mut decoder = Decoder::new(&encoded);
+        assert_eq!(decoder.read_vvec().unwrap(), b"lib/set.rb");
+    }
+
+    #[test]
+    fn truncated_varint_is_eof_not_panic() {
+        // First byte declares a four-byte varint but only two bytes follow.
+        let bytes = [0x80, 0x00];
+        let mut decoder = Decoder::new(&bytes);
+        assert_eq!(decoder.read_varint(), Err(DecodeError::UnexpectedEof));
+    }
+
+    #[test]
+    fn vvec_length_past_end_is_eof() {
+        // Varint length of 10 followed by only three bytes.
+        let bytes = [0x0a, b'a', b'b', b'c'];
+        let mut decoder = Decoder::new(&bytes);
+        assert_eq!(decoder.read_vvec(), Err(DecodeError::UnexpectedEof));
+    }
+
+    #[test]
+    fn cache_round_trips() {
+        let entries = vec![
+            (b"lib/set.rb".to_vec(), b"class Set; end".to_vec()),
+            (b"lib/json.rb".to_vec(), Vec::new()),
+        ];
+        let blob = encode(&entries);
+        assert_eq!(&blob[..4], &MAGIC);
+        assert_eq!(blob[4], VERSION);
+        assert_eq!(decode(&blob).unwrap(), entries);
+    }
+
+    #[test]
+    fn decode_rejects_bad_magic() {
+        let bytes = *b"XXXX\x01";
+        assert_eq!(decode(&bytes), Err(DecodeError::BadMagic));
+    }
+
+    #[test]
+    fn decode_rejects_unsupported_version() {
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(99);
+        bytes.push(0);
+        assert_eq!(decode(&bytes), Err(DecodeError::UnsupportedVersion(99)));
+    }
+}
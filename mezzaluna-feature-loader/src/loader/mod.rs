@@ -1,6 +1,9 @@
 use std::ffi::OsStr;
-use std::io;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 
+pub mod cache;
 #[cfg(feature = "rubylib")]
 mod rubylib;
 
@@ -13,6 +16,7 @@ use crate::loaded_features::LoadedFeatures;
 pub struct Loader {
     #[cfg(feature = "rubylib")]
     rubylib: Rubylib,
+    load_paths: Vec<PathBuf>,
     loaded_features: LoadedFeatures,
 }
 
@@ -25,6 +29,7 @@ impl Loader {
         Some(Self {
             #[cfg(feature = "rubylib")]
             rubylib,
+            load_paths: Vec::new(),
             loaded_features,
         })
     }
@@ -39,6 +44,7 @@ impl Loader {
         let loaded_features = LoadedFeatures::new();
         Some(Self {
             rubylib,
+            load_paths: Vec::new(),
             loaded_features,
         })
     }
@@ -55,34 +61,105 @@ impl Loader {
         let loaded_features = LoadedFeatures::new();
         Some(Self {
             rubylib,
+            load_paths: Vec::new(),
             loaded_features,
         })
     }
 
+    /// Append a directory to the ordered list of load-path roots used to
+    /// resolve relative paths in [`read`](Self::read).
+    ///
+    /// Roots are searched in the order they are pushed, after any `rubylib`
+    /// resolver.
+    #[must_use]
+    pub fn with_load_path<T>(mut self, load_path: T) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        self.load_paths.push(load_path.into());
+        self
+    }
+
+    /// Extend the ordered list of load-path roots with `load_paths`, preserving
+    /// their relative order.
+    #[must_use]
+    pub fn with_load_paths<I, T>(mut self, load_paths: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<PathBuf>,
+    {
+        self.load_paths.extend(load_paths.into_iter().map(Into::into));
+        self
+    }
+
     #[allow(clippy::missing_errors_doc)]
-    #[allow(clippy::missing_panics_doc)]
     pub fn read<T>(&self, path: T) -> io::Result<Vec<u8>>
     where
         T: AsRef<OsStr>,
     {
+        let path = Path::new(&path);
+
         #[cfg(feature = "rubylib")]
         {
-            use std::io::Read;
-            use std::path::Path;
+            if let Some(handle) = self.rubylib.resolve_file(path) {
+                return read_file(handle.as_file());
+            }
+        }
 
-            if let Some(handle) = self.rubylib.resolve_file(Path::new(&path)) {
-                let file = handle.as_file();
-                // Allocate one extra byte so the buffer doesn't need to grow before the
-                // final `read` call at the end of the file.  Don't worry about `usize`
-                // overflow because reading will fail regardless in that case.
-                #[allow(clippy::cast_possible_truncation)]
-                let initial_buffer_size = file.metadata().map(|m| m.len() as usize + 1).unwrap_or(0);
-                let mut buf = Vec::with_capacity(initial_buffer_size);
-                handle.as_file().read_to_end(&mut buf)?;
-                return Ok(buf);
+        // Walk the configured load-path roots and read the first match.
+        for root in &self.load_paths {
+            let candidate = root.join(path);
+            match File::open(&candidate) {
+                Ok(file) => return read_file(&file),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
             }
         }
-        let _ignore_not_implemented = path;
-        unimplemented!("implement Loader::read");
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "could not resolve feature against any load path",
+        ))
+    }
+
+    /// Create `dir` and all of its parents, so a nested cache path is created
+    /// atomically.
+    ///
+    /// On Unix the cache directory is created with mode `0o700` so cached
+    /// sources are not world-readable.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn create_cache_dir<T>(dir: T) -> io::Result<()>
+    where
+        T: AsRef<Path>,
+    {
+        let mut builder = fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        builder.create(dir)
+    }
+}
+
+/// Read the entire contents of `file` into a freshly allocated buffer.
+///
+/// The buffer is sized from [`fs::Metadata::len`] and filled with
+/// [`Read::read_exact`] rather than [`Read::read_to_end`], so that a file which
+/// is truncated or grown concurrently surfaces as an error instead of being
+/// silently mis-read. A short read is mapped to [`io::ErrorKind::UnexpectedEof`].
+fn read_file(mut file: &File) -> io::Result<Vec<u8>> {
+    #[allow(clippy::cast_possible_truncation)]
+    let len = file.metadata()?.len() as usize;
+    let mut buf = vec![0; len];
+    file.read_exact(&mut buf)?;
+    // A file that grew after its length was taken still has bytes to read.
+    if file.read(&mut [0_u8; 1])? != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "file grew while it was being read",
+        ));
     }
-}
\ No newline at end of file
+    Ok(buf)
+}
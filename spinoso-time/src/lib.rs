@@ -17,6 +17,7 @@
 // This approach is borrowed from tokio.
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, feature(doc_alias))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! Time is an abstraction of dates and times.
 //!
@@ -43,13 +44,35 @@
 //! - `chrono` which is backed by the [`chrono`] crate
 //! - `tzrs` which is backed by the [`tz-rs`] crate
 //!
-//! This crate requires [`std`], the Rust Standard Library.
+//! # `no_std`
+//!
+//! Following chrono's split into `std`/`alloc`/core builds, the timestamp core
+//! of this crate builds on `no_std` targets. The crate is `#![no_std]` unless
+//! the `std` feature is enabled. The string and [`ToA`] outputs allocate and
+//! are always built, so either the `alloc` or the `std` feature must be
+//! enabled. The system clock and local timezone lookup need the host
+//! platform, so `Time::now` and `Offset::local` require the `std` feature
+//! and are unavailable in a bare `alloc` build. A `no_std` + `alloc` target
+//! that supplies its own clock can still perform timestamp arithmetic and
+//! formatting.
+//!
+//! [`std`]: https://doc.rust-lang.org/std/
 //!
 //! [`Time`]: https://ruby-doc.org/core-2.6.3/Time.html
 //! [`chrono`]: https://crates.io/crates/chrono
 //! [`tz-rs`]: https://crates.io/crates/tz-rs
 //! [`tzdb`]: https://crates.io/crates/tzdb
 
+// The `Time` core (timestamp arithmetic, comparisons) needs only integer math,
+// but the string and `ToA` outputs allocate, so the crate depends on `alloc`.
+// `Time::now` / `Offset::local` are the only genuinely `std`-dependent pieces
+// and are gated behind the `std` feature.
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate alloc;
+
 // Ensure code blocks in `README.md` compile
 #[cfg(doctest)]
 #[doc = include_str!("../README.md")]
@@ -81,7 +104,7 @@ cfg_if::cfg_if! {
     } else if #[cfg(feature = "chrono")] {
         pub use time::chrono::{ComponentOutOfRangeError, Offset, Time, ToA};
     } else if #[cfg(feature = "tzrs")] {
-        pub use time::tzrs::{Offset, Time, ToA};
+        pub use time::tzrs::{Offset, ParseError, SecondsFormat, Time, TimeResult, ToA};
     }
 }
 
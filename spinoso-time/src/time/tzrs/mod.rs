@@ -7,14 +7,22 @@ mod build;
 mod convert;
 mod math;
 mod offset;
+mod parse;
 mod parts;
+mod rfc;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+mod strftime;
 mod timezone;
 mod to_a;
 
 pub use offset::Offset;
+pub use parse::ParseError;
+pub use rfc::SecondsFormat;
 pub use to_a::ToA;
 
-use crate::NANOS_IN_SECOND;
+use crate::{MICROS_IN_NANO, NANOS_IN_SECOND};
 
 /// Implementation of Ruby [`Time`], a timezone-aware datetime, based on
 /// [`tz-rs`] and [`tzdb`].
@@ -62,6 +70,59 @@ pub struct Time {
     offset: Offset,
 }
 
+/// The result of resolving a wall-clock time against a timezone.
+///
+/// A local date and time is not always a single unambiguous instant. During a
+/// DST spring-forward the wall clock skips an interval that never occurs
+/// ([`TimeResult::None`]); during a fall-back it repeats an interval, so the
+/// same wall clock maps to two UTC instants ([`TimeResult::Ambiguous`]).
+///
+/// This mirrors chrono's `LocalResult` and is returned by [`Time::try_new`] so
+/// callers can surface an error instead of panicking.
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub enum TimeResult {
+    /// The wall-clock time does not exist (a spring-forward gap).
+    None,
+    /// The wall-clock time maps to a single unambiguous instant.
+    Single(Time),
+    /// The wall-clock time is ambiguous (a fall-back overlap). The first
+    /// `Time` is the earlier UTC instant and the second is the later one.
+    Ambiguous(Time, Time),
+}
+
+impl TimeResult {
+    /// Returns the earliest matching `Time`, or `None` for a gap.
+    #[inline]
+    #[must_use]
+    pub fn earliest(self) -> Option<Time> {
+        match self {
+            TimeResult::None => None,
+            TimeResult::Single(time) | TimeResult::Ambiguous(time, _) => Some(time),
+        }
+    }
+
+    /// Returns the latest matching `Time`, or `None` for a gap.
+    #[inline]
+    #[must_use]
+    pub fn latest(self) -> Option<Time> {
+        match self {
+            TimeResult::None => None,
+            TimeResult::Single(time) | TimeResult::Ambiguous(_, time) => Some(time),
+        }
+    }
+
+    /// Returns the matching `Time` only when it is unambiguous.
+    #[inline]
+    #[must_use]
+    pub fn single(self) -> Option<Time> {
+        match self {
+            TimeResult::Single(time) => Some(time),
+            TimeResult::None | TimeResult::Ambiguous(..) => None,
+        }
+    }
+}
+
 impl Hash for Time {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -114,6 +175,14 @@ impl Time {
     /// Note: During DST transitions, a specific time can be ambiguous. This
     /// method will always pick the earliest date.
     ///
+    /// # Panics
+    ///
+    /// Panics if the given wall-clock values do not resolve to any instant in
+    /// `offset` — for example a time that falls in a spring-forward DST gap, or
+    /// field values that are out of range. Callers that must handle these cases
+    /// without aborting should use the fallible [`Time::try_new`], which
+    /// returns a [`TimeResult`] instead of panicking.
+    ///
     /// # Examples
     /// ```
     /// use spinoso_time::tzrs::{Time, Offset};
@@ -136,13 +205,57 @@ impl Time {
         nanoseconds: u32,
         offset: Offset,
     ) -> Self {
+        Self::try_new(year, month, day, hour, minute, second, nanoseconds, offset)
+            .earliest()
+            .expect("Could not find a matching DateTime for this timezone")
+    }
+
+    /// Returns a [`TimeResult`] describing how the given wall-clock values
+    /// resolve in the provided `offset`, without panicking on DST transitions.
+    ///
+    /// During a spring-forward the requested time does not exist and
+    /// [`TimeResult::None`] is returned; during a fall-back it is ambiguous and
+    /// both the earlier and later UTC instants are returned via
+    /// [`TimeResult::Ambiguous`]. [`Time::new`] is a thin wrapper that resolves
+    /// an ambiguous result to its earliest instant.
+    ///
+    /// # Examples
+    /// ```
+    /// use spinoso_time::tzrs::{Offset, Time, TimeResult};
+    /// let offset = Offset::utc();
+    /// match Time::try_new(2022, 9, 25, 1, 30, 0, 0, offset) {
+    ///     TimeResult::Single(time) => println!("{}", time.to_int()),
+    ///     TimeResult::Ambiguous(earlier, _later) => println!("{}", earlier.to_int()),
+    ///     TimeResult::None => println!("nonexistent local time"),
+    /// }
+    /// ```
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanoseconds: u32,
+        offset: Offset,
+    ) -> TimeResult {
         let tz = offset.time_zone_ref();
-        let found_date_times = DateTime::find(year, month, day, hour, minute, second, nanoseconds, tz)
-            .expect("Could not find a matching DateTime for this timezone");
-        let dt = found_date_times
-            .unique()
-            .expect("Could not find a matching DateTime for this timezone");
-        Self { inner: dt, offset }
+        let found_date_times = match DateTime::find(year, month, day, hour, minute, second, nanoseconds, tz) {
+            Ok(found) => found,
+            Err(_) => return TimeResult::None,
+        };
+        match (found_date_times.earliest(), found_date_times.latest()) {
+            (Some(earliest), Some(latest)) if earliest == latest => {
+                TimeResult::Single(Self { inner: earliest, offset })
+            }
+            (Some(earliest), Some(latest)) => TimeResult::Ambiguous(
+                Self { inner: earliest, offset },
+                Self { inner: latest, offset },
+            ),
+            _ => TimeResult::None,
+        }
     }
 
     /// Returns a Time with the current time in the System Timezone.
@@ -158,6 +271,8 @@ impl Time {
     ///
     /// [`Time#now`]: https://ruby-doc.org/core-2.6.3/Time.html#method-c-now
     #[inline]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn now() -> Self {
         let offset = Offset::local();
         let time_zone_ref = offset.time_zone_ref();
@@ -186,6 +301,84 @@ impl Time {
         let dt = DateTime::from_timespec(seconds, nanoseconds, time_zone_ref).expect("Could not create datetime");
         Self { inner: dt, offset }
     }
+
+    /// Returns a Time in the given timezone from the number of `milliseconds`
+    /// since the Epoch.
+    ///
+    /// Can be used to implement ruby [`Time#at`] with `:millisecond` units.
+    /// Negative inputs floor toward negative infinity, so `-1` becomes
+    /// `seconds = -1` with `999_000_000` nanoseconds rather than a negative
+    /// subsecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_time::tzrs::{Time, Offset};
+    /// let t = Time::at_millis(-1, Offset::utc());
+    /// assert_eq!(t.to_int(), -1);
+    /// assert_eq!(t.nanoseconds(), 999_000_000);
+    /// ```
+    ///
+    /// [`Time#at`]: https://ruby-doc.org/core-2.6.3/Time.html#method-c-at
+    #[inline]
+    pub fn at_millis(milliseconds: i64, offset: Offset) -> Self {
+        let millis_in_second = i64::from(NANOS_IN_SECOND / 1_000_000);
+        let seconds = milliseconds.div_euclid(millis_in_second);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // remainder is in `0..1_000`
+        let nanoseconds = milliseconds.rem_euclid(millis_in_second) as u32 * 1_000_000;
+        Self::with_timespec_and_offset(seconds, nanoseconds, offset)
+    }
+
+    /// Returns a Time in the given timezone from the number of `microseconds`
+    /// since the Epoch.
+    ///
+    /// Can be used to implement ruby [`Time#at`] with `:microsecond` units.
+    /// Negative inputs floor toward negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_time::tzrs::{Time, Offset};
+    /// let t = Time::at_micros(-1, Offset::utc());
+    /// assert_eq!(t.to_int(), -1);
+    /// assert_eq!(t.nanoseconds(), 999_999_000);
+    /// ```
+    ///
+    /// [`Time#at`]: https://ruby-doc.org/core-2.6.3/Time.html#method-c-at
+    #[inline]
+    pub fn at_micros(microseconds: i64, offset: Offset) -> Self {
+        let micros_in_second = i64::from(NANOS_IN_SECOND / MICROS_IN_NANO);
+        let seconds = microseconds.div_euclid(micros_in_second);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // remainder is in `0..1_000_000`
+        let nanoseconds = microseconds.rem_euclid(micros_in_second) as u32 * MICROS_IN_NANO;
+        Self::with_timespec_and_offset(seconds, nanoseconds, offset)
+    }
+
+    /// Returns a Time in the given timezone from the number of `nanoseconds`
+    /// since the Epoch.
+    ///
+    /// Can be used to implement ruby [`Time#at`] with `:nanosecond` units.
+    /// Negative inputs floor toward negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_time::tzrs::{Time, Offset};
+    /// let t = Time::at_nanos(-1, Offset::utc());
+    /// assert_eq!(t.to_int(), -1);
+    /// assert_eq!(t.nanoseconds(), 999_999_999);
+    /// ```
+    ///
+    /// [`Time#at`]: https://ruby-doc.org/core-2.6.3/Time.html#method-c-at
+    #[inline]
+    pub fn at_nanos(nanoseconds: i128, offset: Offset) -> Self {
+        let nanos_in_second = i128::from(NANOS_IN_SECOND);
+        #[allow(clippy::cast_possible_truncation)] // seconds fit in `i64` for any representable `Time`
+        let seconds = nanoseconds.div_euclid(nanos_in_second) as i64;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // remainder is in `0..NANOS_IN_SECOND`
+        let subsec = nanoseconds.rem_euclid(nanos_in_second) as u32;
+        Self::with_timespec_and_offset(seconds, subsec, offset)
+    }
 }
 
 impl From<ToA> for Time {
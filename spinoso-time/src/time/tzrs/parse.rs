@@ -0,0 +1,461 @@
+use core::fmt;
+
+use super::{Offset, Time};
+
+/// Error returned by the [`Time`] string parsers when the input does not match
+/// the expected grammar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input ended before a complete timestamp could be read.
+    UnexpectedEof,
+    /// A literal or numeric field did not match the expected shape.
+    Invalid,
+    /// A `strptime` conversion directive failed to match the input. The byte
+    /// is the directive letter that failed.
+    Directive(u8),
+    /// The parsed fields do not describe a valid point in time.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedEof => f.write_str("unexpected end of input while parsing time"),
+            ParseError::Invalid => f.write_str("input did not match the expected time format"),
+            ParseError::Directive(byte) => write!(f, "format directive %{} did not match input", char::from(*byte)),
+            ParseError::OutOfRange => f.write_str("parsed time fields are out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseError {}
+
+/// Intermediate bag of fields accumulated while parsing, before they are fed
+/// into a [`Time`] constructor.
+#[derive(Debug, Clone, Copy, Default)]
+struct Parsed {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanoseconds: u32,
+    /// Offset east of UTC in seconds when an explicit zone was parsed.
+    zone: Option<i32>,
+}
+
+impl Parsed {
+    /// Resolve the accumulated fields into a [`Time`].
+    ///
+    /// When an explicit `zone` was parsed the resulting `Time` carries a fixed
+    /// offset; otherwise it falls back to `default_offset`.
+    fn into_time(self, default_offset: Offset) -> Result<Time, ParseError> {
+        if let Some(seconds) = self.zone {
+            let unix = civil_to_unix(self.year, self.month, self.day, self.hour, self.minute, self.second)?;
+            let offset = if seconds == 0 { Offset::utc() } else { Offset::fixed(seconds) };
+            Ok(Time::with_timespec_and_offset(unix - i64::from(seconds), self.nanoseconds, offset))
+        } else {
+            // Go through the fallible `try_new` so malformed field combinations
+            // (e.g. a format that omits the date, leaving `month`/`day` at zero)
+            // surface as a `ParseError` rather than panicking in `Time::new`.
+            Time::try_new(
+                self.year,
+                self.month,
+                self.day,
+                self.hour,
+                self.minute,
+                self.second,
+                self.nanoseconds,
+                default_offset,
+            )
+            .earliest()
+            .ok_or(ParseError::OutOfRange)
+        }
+    }
+}
+
+/// Days from the Unix epoch to the given proleptic Gregorian date.
+///
+/// Uses Howard Hinnant's `days_from_civil` algorithm, which is valid for the
+/// entire range of [`Time`].
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let y = i64::from(if month <= 2 { year - 1 } else { year });
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let m = i64::from(month);
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Number of days in `month` of `year`, accounting for leap years.
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Convert a wall-clock date and time to seconds since the Unix epoch,
+/// assuming UTC. Callers subtract the zone offset separately.
+fn civil_to_unix(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Result<i64, ParseError> {
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        // A leap second is permitted in the `60` slot, matching Ruby.
+        || second > 60
+    {
+        return Err(ParseError::OutOfRange);
+    }
+    let days = days_from_civil(year, month, day);
+    Ok(days * 86_400 + i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second))
+}
+
+impl Time {
+    /// Parses an RFC 3339 (ISO 8601) timestamp into a `Time`.
+    ///
+    /// Accepts either a space or `T` between the date and time (chrono permits
+    /// this so that `to_string().parse()` round-trips), a trailing `Z` or a
+    /// numeric `±HH:MM` offset, and an optional fractional-seconds field of any
+    /// length (truncated or padded to nanoseconds). Backs Ruby's
+    /// [`Time.iso8601`].
+    ///
+    /// [`Time.iso8601`]: https://ruby-doc.org/stdlib-2.6.3/libdoc/time/rdoc/Time.html#method-c-iso8601
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if `input` is not a well-formed RFC 3339
+    /// timestamp.
+    pub fn parse_rfc3339(input: &[u8]) -> Result<Time, ParseError> {
+        let mut cursor = Cursor::new(input);
+        let mut parsed = Parsed::default();
+
+        parsed.year = cursor.signed_number(4)?;
+        cursor.literal(b'-')?;
+        parsed.month = cursor.number(2)?;
+        cursor.literal(b'-')?;
+        parsed.day = cursor.number(2)?;
+        match cursor.next() {
+            Some(b'T' | b't' | b' ') => {}
+            _ => return Err(ParseError::Invalid),
+        }
+        parsed.hour = cursor.number(2)?;
+        cursor.literal(b':')?;
+        parsed.minute = cursor.number(2)?;
+        cursor.literal(b':')?;
+        parsed.second = cursor.number(2)?;
+        if cursor.peek() == Some(b'.') {
+            cursor.next();
+            parsed.nanoseconds = cursor.fractional_nanos()?;
+        }
+        parsed.zone = Some(cursor.offset()?);
+        if !cursor.is_empty() {
+            return Err(ParseError::Invalid);
+        }
+        parsed.into_time(Offset::utc())
+    }
+
+    /// Parses an RFC 2822 timestamp into a `Time`.
+    ///
+    /// The expected shape is `Wdy, DD Mon YYYY HH:MM:SS ±HHMM` with the fixed
+    /// English day and month abbreviations. Following chrono's RFC 2822 fix, a
+    /// `-0000` zone parses as UTC. Backs Ruby's [`Time.rfc2822`].
+    ///
+    /// [`Time.rfc2822`]: https://ruby-doc.org/stdlib-2.6.3/libdoc/time/rdoc/Time.html#method-c-rfc2822
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if `input` is not a well-formed RFC 2822
+    /// timestamp.
+    pub fn parse_rfc2822(input: &[u8]) -> Result<Time, ParseError> {
+        let mut cursor = Cursor::new(input);
+        let mut parsed = Parsed::default();
+
+        // Optional leading `Wdy, `.
+        if input.get(3) == Some(&b',') {
+            cursor.skip(3);
+            cursor.literal(b',')?;
+            cursor.literal(b' ')?;
+        }
+        parsed.day = cursor.number(2)?;
+        cursor.literal(b' ')?;
+        parsed.month = cursor.month_abbrev()?;
+        cursor.literal(b' ')?;
+        parsed.year = cursor.signed_number(4)?;
+        cursor.literal(b' ')?;
+        parsed.hour = cursor.number(2)?;
+        cursor.literal(b':')?;
+        parsed.minute = cursor.number(2)?;
+        cursor.literal(b':')?;
+        parsed.second = cursor.number(2)?;
+        cursor.literal(b' ')?;
+        parsed.zone = Some(cursor.numeric_zone()?);
+        parsed.into_time(Offset::utc())
+    }
+
+    /// Parses `input` according to the `strftime`-style directives in `fmt`.
+    ///
+    /// Accumulates the matched fields and then constructs the `Time` in
+    /// `offset` (unless a zone directive supplied its own). Backs Ruby's
+    /// [`Time.strptime`].
+    ///
+    /// Only a subset of the [`Time::strftime`] directives is recognized: `%Y`,
+    /// `%m`, `%d`, `%e`, `%H`, `%M`, `%S`, `%N`, `%L`, `%z`, and `%%`. Any other
+    /// directive, and any flag or field width, fails with
+    /// [`ParseError::Directive`]. `%z` accepts `Z` or `±HH:MM`, not the `±HHMM`
+    /// form that `strftime` emits.
+    ///
+    /// [`Time.strptime`]: https://ruby-doc.org/stdlib-2.6.3/libdoc/time/rdoc/Time.html#method-c-strptime
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Directive`] identifying the directive that failed
+    /// to match, or another [`ParseError`] variant for malformed input.
+    pub fn strptime(input: &[u8], fmt: &[u8], offset: Offset) -> Result<Time, ParseError> {
+        let mut cursor = Cursor::new(input);
+        let mut parsed = Parsed::default();
+        let mut fmt = fmt.iter().copied().peekable();
+
+        while let Some(byte) = fmt.next() {
+            if byte != b'%' {
+                cursor.literal(byte)?;
+                continue;
+            }
+            let directive = fmt.next().ok_or(ParseError::Invalid)?;
+            match directive {
+                b'Y' => parsed.year = cursor.signed_number(4).map_err(|_| ParseError::Directive(b'Y'))?,
+                b'm' => parsed.month = cursor.number(2).map_err(|_| ParseError::Directive(b'm'))?,
+                b'd' | b'e' => parsed.day = cursor.number(2).map_err(|_| ParseError::Directive(directive))?,
+                b'H' => parsed.hour = cursor.number(2).map_err(|_| ParseError::Directive(b'H'))?,
+                b'M' => parsed.minute = cursor.number(2).map_err(|_| ParseError::Directive(b'M'))?,
+                b'S' => parsed.second = cursor.number(2).map_err(|_| ParseError::Directive(b'S'))?,
+                b'N' | b'L' => {
+                    parsed.nanoseconds = cursor.fractional_nanos().map_err(|_| ParseError::Directive(directive))?;
+                }
+                b'z' => parsed.zone = Some(cursor.offset().map_err(|_| ParseError::Directive(b'z'))?),
+                b'%' => cursor.literal(b'%').map_err(|_| ParseError::Directive(b'%'))?,
+                other => return Err(ParseError::Directive(other)),
+            }
+        }
+        parsed.into_time(offset)
+    }
+}
+
+/// A read cursor over the input bytes with small grammar helpers.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.bytes.get(self.pos).copied();
+        if byte.is_some() {
+            self.pos += 1;
+        }
+        byte
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.pos = (self.pos + n).min(self.bytes.len());
+    }
+
+    fn literal(&mut self, expected: u8) -> Result<(), ParseError> {
+        match self.next() {
+            Some(byte) if byte == expected => Ok(()),
+            Some(_) => Err(ParseError::Invalid),
+            None => Err(ParseError::UnexpectedEof),
+        }
+    }
+
+    /// Read up to `max` ASCII digits and parse them as an unsigned value.
+    fn digits(&mut self, max: usize) -> Result<u64, ParseError> {
+        let mut value = 0_u64;
+        let mut read = 0;
+        while read < max {
+            match self.peek() {
+                Some(digit @ b'0'..=b'9') => {
+                    value = value.saturating_mul(10).saturating_add(u64::from(digit - b'0'));
+                    self.pos += 1;
+                    read += 1;
+                }
+                _ => break,
+            }
+        }
+        if read == 0 {
+            return Err(ParseError::UnexpectedEof);
+        }
+        Ok(value)
+    }
+
+    fn number<T>(&mut self, max: usize) -> Result<T, ParseError>
+    where
+        T: TryFrom<u64>,
+    {
+        T::try_from(self.digits(max)?).map_err(|_| ParseError::OutOfRange)
+    }
+
+    /// Read an optionally signed integer such as a year.
+    fn signed_number<T>(&mut self, max: usize) -> Result<T, ParseError>
+    where
+        T: TryFrom<i64>,
+    {
+        let negative = match self.peek() {
+            Some(b'-') => {
+                self.pos += 1;
+                true
+            }
+            Some(b'+') => {
+                self.pos += 1;
+                false
+            }
+            _ => false,
+        };
+        let magnitude = i64::try_from(self.digits(max)?).map_err(|_| ParseError::OutOfRange)?;
+        let value = if negative { -magnitude } else { magnitude };
+        T::try_from(value).map_err(|_| ParseError::OutOfRange)
+    }
+
+    /// Read a run of fractional-second digits and scale to nanoseconds,
+    /// truncating or padding to nine digits.
+    fn fractional_nanos(&mut self) -> Result<u32, ParseError> {
+        let mut nanos = 0_u32;
+        let mut scale = 100_000_000_u32;
+        let mut read = 0;
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+            read += 1;
+            if scale > 0 {
+                nanos += u32::from(digit - b'0') * scale;
+                scale /= 10;
+            }
+        }
+        if read == 0 {
+            return Err(ParseError::UnexpectedEof);
+        }
+        Ok(nanos)
+    }
+
+    /// Read a `Z` or numeric `±HH:MM` offset as seconds east of UTC.
+    fn offset(&mut self) -> Result<i32, ParseError> {
+        match self.peek() {
+            Some(b'Z' | b'z') => {
+                self.pos += 1;
+                Ok(0)
+            }
+            Some(b'+' | b'-') => {
+                let negative = self.next() == Some(b'-');
+                let hours: i32 = self.number(2)?;
+                self.literal(b':')?;
+                let minutes: i32 = self.number(2)?;
+                let seconds = hours * 3600 + minutes * 60;
+                Ok(if negative { -seconds } else { seconds })
+            }
+            Some(_) => Err(ParseError::Invalid),
+            None => Err(ParseError::UnexpectedEof),
+        }
+    }
+
+    /// Read a numeric `±HHMM` zone as seconds east of UTC. A `-0000` zone
+    /// parses as UTC per chrono's RFC 2822 fix.
+    fn numeric_zone(&mut self) -> Result<i32, ParseError> {
+        let negative = match self.next() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => return Err(ParseError::Invalid),
+        };
+        let hours: i32 = self.number(2)?;
+        let minutes: i32 = self.number(2)?;
+        let seconds = hours * 3600 + minutes * 60;
+        if negative && seconds == 0 {
+            return Ok(0);
+        }
+        Ok(if negative { -seconds } else { seconds })
+    }
+
+    /// Match a three-letter English month abbreviation to its month number.
+    fn month_abbrev(&mut self) -> Result<u8, ParseError> {
+        const MONTHS: [&[u8]; 12] = [
+            b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov", b"Dec",
+        ];
+        let end = self.pos + 3;
+        let slice = self.bytes.get(self.pos..end).ok_or(ParseError::UnexpectedEof)?;
+        let month = MONTHS
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(slice))
+            .ok_or(ParseError::Invalid)?;
+        self.pos = end;
+        #[allow(clippy::cast_possible_truncation)] // `month` is in `0..12`
+        Ok(month as u8 + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc3339_parses_utc_designator() {
+        let t = Time::parse_rfc3339(b"1970-01-01T00:01:00Z").unwrap();
+        assert_eq!(t.to_int(), 60);
+    }
+
+    #[test]
+    fn rfc3339_accepts_space_separator() {
+        let t = Time::parse_rfc3339(b"1970-01-01 00:01:00+00:00").unwrap();
+        assert_eq!(t.to_int(), 60);
+    }
+
+    #[test]
+    fn rfc3339_parses_fractional_seconds() {
+        let t = Time::parse_rfc3339(b"1970-01-01T00:00:00.5Z").unwrap();
+        assert_eq!(t.subsec_fractional().0, 500_000_000);
+    }
+
+    #[test]
+    fn rfc2822_negative_zero_is_utc() {
+        let t = Time::parse_rfc2822(b"Thu, 01 Jan 1970 00:01:00 -0000").unwrap();
+        assert_eq!(t.to_int(), 60);
+    }
+
+    #[test]
+    fn strptime_reports_failing_directive() {
+        let err = Time::strptime(b"1970-XX-01", b"%Y-%m-%d", Offset::utc()).unwrap_err();
+        assert_eq!(err, ParseError::Directive(b'm'));
+    }
+
+    #[test]
+    fn rfc3339_rejects_out_of_range_clock() {
+        let err = Time::parse_rfc3339(b"1970-01-01T99:99:99Z").unwrap_err();
+        assert_eq!(err, ParseError::OutOfRange);
+        let err = Time::parse_rfc3339(b"1970-02-30T00:00:00Z").unwrap_err();
+        assert_eq!(err, ParseError::OutOfRange);
+    }
+
+    #[test]
+    fn strptime_missing_date_is_out_of_range() {
+        // `%H` alone leaves `month`/`day` at zero; this must be reported rather
+        // than panicking while constructing the `Time`.
+        let err = Time::strptime(b"12", b"%H", Offset::utc()).unwrap_err();
+        assert_eq!(err, ParseError::OutOfRange);
+    }
+}
@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+
+use super::strftime::Components;
+use super::Time;
+
+/// Specifies how the fractional-seconds portion of an RFC 3339 timestamp is
+/// rendered.
+///
+/// This mirrors chrono's `SecondsFormat` so callers can request the same
+/// trade-off between precision and brevity when implementing Ruby's
+/// [`Time#iso8601`] and [`Time#xmlschema`].
+///
+/// [`Time#iso8601`]: https://ruby-doc.org/stdlib-2.6.3/libdoc/time/rdoc/Time.html#method-i-iso8601
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecondsFormat {
+    /// Omit the decimal point and fractional seconds entirely.
+    Secs,
+    /// Always emit exactly three fractional digits (milliseconds).
+    Millis,
+    /// Always emit exactly six fractional digits (microseconds).
+    Micros,
+    /// Always emit exactly nine fractional digits (nanoseconds).
+    Nanos,
+    /// Emit `0`, `3`, `6`, or `9` fractional digits, whichever is the smallest
+    /// that losslessly represents the stored nanoseconds.
+    AutoSi,
+}
+
+impl SecondsFormat {
+    /// Number of fractional digits to render for `nanoseconds`, or `None` when
+    /// the decimal point should be omitted.
+    fn digits(self, nanoseconds: u32) -> Option<usize> {
+        match self {
+            SecondsFormat::Secs => None,
+            SecondsFormat::Millis => Some(3),
+            SecondsFormat::Micros => Some(6),
+            SecondsFormat::Nanos => Some(9),
+            SecondsFormat::AutoSi => {
+                if nanoseconds == 0 {
+                    None
+                } else if nanoseconds % 1_000_000 == 0 {
+                    Some(3)
+                } else if nanoseconds % 1_000 == 0 {
+                    Some(6)
+                } else {
+                    Some(9)
+                }
+            }
+        }
+    }
+}
+
+/// Abbreviated English weekday names indexed by day of the week, Sunday first.
+const DAY_ABBREV: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Abbreviated English month names indexed by month number minus one.
+const MONTH_ABBREV: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+impl Time {
+    /// Formats _time_ as an RFC 2822 date-time string.
+    ///
+    /// The output has the shape `Wdy, DD Mon YYYY HH:MM:SS ±HHMM`, always using
+    /// the fixed English day and month abbreviations and a numeric zone (never
+    /// a named zone). This backs Ruby's [`Time#rfc2822`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_time::tzrs::Time;
+    /// let t = Time::utc(1970, 1, 1, 0, 1, 0, 0);
+    /// assert_eq!(t.to_rfc2822(), b"Thu, 01 Jan 1970 00:01:00 +0000".to_vec());
+    /// ```
+    ///
+    /// [`Time#rfc2822`]: https://ruby-doc.org/stdlib-2.6.3/libdoc/time/rdoc/Time.html#method-i-rfc2822
+    #[must_use]
+    pub fn to_rfc2822(&self) -> Vec<u8> {
+        let c = Components::from_time(self);
+        let (sign, hh, mm) = offset_hms(c.utc_offset);
+        let formatted = format!(
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+            DAY_ABBREV[usize::from(c.week_day)],
+            c.day,
+            MONTH_ABBREV[usize::from(c.month - 1)],
+            c.year,
+            c.hour,
+            c.minute,
+            c.second,
+            sign,
+            hh,
+            mm,
+        );
+        formatted.into_bytes()
+    }
+
+    /// Formats _time_ as an RFC 3339 (ISO 8601) date-time string with the
+    /// requested fractional-seconds precision.
+    ///
+    /// The output has the shape `YYYY-MM-DDTHH:MM:SS[.fff]±HH:MM`, using `Z` in
+    /// place of the offset when _time_ is UTC. This backs Ruby's
+    /// [`Time#iso8601`] and [`Time#xmlschema`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_time::tzrs::{SecondsFormat, Time};
+    /// let t = Time::utc(1970, 1, 1, 0, 1, 0, 0);
+    /// assert_eq!(t.to_rfc3339(SecondsFormat::Secs), b"1970-01-01T00:01:00Z".to_vec());
+    /// ```
+    ///
+    /// [`Time#iso8601`]: https://ruby-doc.org/stdlib-2.6.3/libdoc/time/rdoc/Time.html#method-i-iso8601
+    #[must_use]
+    pub fn to_rfc3339(&self, secfmt: SecondsFormat) -> Vec<u8> {
+        let c = Components::from_time(self);
+        let mut out = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            c.year, c.month, c.day, c.hour, c.minute, c.second
+        );
+        if let Some(digits) = secfmt.digits(c.nanoseconds) {
+            let nanos = format!("{:09}", c.nanoseconds);
+            out.push('.');
+            out.push_str(&nanos[..digits]);
+        }
+        if c.utc_offset == 0 {
+            out.push('Z');
+        } else {
+            let (sign, hh, mm) = offset_hms(c.utc_offset);
+            out.push_str(&format!("{sign}{hh:02}:{mm:02}"));
+        }
+        out.into_bytes()
+    }
+}
+
+/// Split an offset in seconds into its sign and whole hour/minute magnitudes.
+fn offset_hms(offset: i32) -> (char, u32, u32) {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let abs = offset.unsigned_abs();
+    (sign, abs / 3600, (abs % 3600) / 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc2822_utc() {
+        let t = Time::utc(1970, 1, 1, 0, 1, 0, 0);
+        assert_eq!(t.to_rfc2822(), b"Thu, 01 Jan 1970 00:01:00 +0000".to_vec());
+    }
+
+    #[test]
+    fn rfc3339_uses_z_for_utc() {
+        let t = Time::utc(1970, 1, 1, 0, 1, 0, 0);
+        assert_eq!(t.to_rfc3339(SecondsFormat::Secs), b"1970-01-01T00:01:00Z".to_vec());
+    }
+
+    #[test]
+    fn rfc3339_fixed_precision() {
+        let t = Time::utc(1970, 1, 1, 0, 0, 0, 123_456_789);
+        assert_eq!(t.to_rfc3339(SecondsFormat::Millis), b"1970-01-01T00:00:00.123Z".to_vec());
+        assert_eq!(t.to_rfc3339(SecondsFormat::Nanos), b"1970-01-01T00:00:00.123456789Z".to_vec());
+    }
+
+    #[test]
+    fn rfc3339_auto_si_picks_smallest() {
+        let millis = Time::utc(1970, 1, 1, 0, 0, 0, 120_000_000);
+        assert_eq!(millis.to_rfc3339(SecondsFormat::AutoSi), b"1970-01-01T00:00:00.120Z".to_vec());
+        let secs = Time::utc(1970, 1, 1, 0, 0, 0, 0);
+        assert_eq!(secs.to_rfc3339(SecondsFormat::AutoSi), b"1970-01-01T00:00:00Z".to_vec());
+    }
+}
@@ -0,0 +1,273 @@
+//! Optional [`serde`] support for [`Time`], [`Offset`], and [`ToA`], gated
+//! behind the `serde` feature.
+//!
+//! The default [`Serialize`]/[`Deserialize`] implementations render a `Time`
+//! as an RFC 3339 string (reusing the formatter and parser from this crate),
+//! which preserves the offset across a round-trip. An [`Offset`] serializes as
+//! its seconds east of UTC and a [`ToA`] as its broken-down calendar fields.
+//! Two opt-in submodules, [`timestamp`] and [`timestamp_nanos`], offer the
+//! numeric representations chrono exposes, usable through `#[serde(with = ...)]`.
+//!
+//! [`serde`]: https://crates.io/crates/serde
+//! [`Serialize`]: ::serde::Serialize
+//! [`Deserialize`]: ::serde::Deserialize
+
+use core::fmt;
+
+use ::serde::de::{self, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Offset, SecondsFormat, Time, ToA};
+
+impl Serialize for Time {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let rendered = self.to_rfc3339(SecondsFormat::AutoSi);
+        // RFC 3339 output is always valid UTF-8.
+        let rendered = core::str::from_utf8(&rendered).map_err(::serde::ser::Error::custom)?;
+        serializer.serialize_str(rendered)
+    }
+}
+
+impl<'de> Deserialize<'de> for Time {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Rfc3339Visitor;
+
+        impl Visitor<'_> for Rfc3339Visitor {
+            type Value = Time;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an RFC 3339 formatted date and time string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Time::parse_rfc3339(value.as_bytes()).map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Rfc3339Visitor)
+    }
+}
+
+/// Serialize and deserialize a [`Time`] as Unix seconds (`i64`).
+///
+/// Intended for use with `#[serde(with = "spinoso_time::tzrs::serde::timestamp")]`.
+/// The deserialized `Time` is reconstructed in UTC.
+pub mod timestamp {
+    use super::{Offset, Time};
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize `time` as the number of whole seconds since the Unix epoch.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(time.to_int())
+    }
+
+    /// Deserialize a `Time` in UTC from Unix seconds.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let seconds = i64::deserialize(deserializer)?;
+        Ok(Time::with_timespec_and_offset(seconds, 0, Offset::utc()))
+    }
+}
+
+/// Serialize and deserialize a [`Time`] as total nanoseconds since the epoch
+/// (`i128`).
+///
+/// Intended for use with
+/// `#[serde(with = "spinoso_time::tzrs::serde::timestamp_nanos")]`. The
+/// deserialized `Time` is reconstructed in UTC.
+pub mod timestamp_nanos {
+    use super::{Offset, Time};
+    use crate::NANOS_IN_SECOND;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize `time` as the total number of nanoseconds since the epoch.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let nanos = i128::from(time.to_int()) * i128::from(NANOS_IN_SECOND) + i128::from(time.subsec_fractional().0);
+        serializer.serialize_i128(nanos)
+    }
+
+    /// Deserialize a `Time` in UTC from total nanoseconds since the epoch.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let total = i128::deserialize(deserializer)?;
+        let nanos_in_second = i128::from(NANOS_IN_SECOND);
+        let seconds = total.div_euclid(nanos_in_second);
+        let subsec = total.rem_euclid(nanos_in_second);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let subsec = subsec as u32;
+        #[allow(clippy::cast_possible_truncation)]
+        let seconds = seconds as i64;
+        Ok(Time::with_timespec_and_offset(seconds, subsec, Offset::utc()))
+    }
+}
+
+impl Serialize for Offset {
+    /// Serializes the offset as the number of seconds east of UTC.
+    ///
+    /// A named time zone is rendered as its offset at the Unix epoch, so a
+    /// round-trip yields the equivalent fixed offset rather than the original
+    /// zone.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let seconds = self
+            .time_zone_ref()
+            .find_local_time_type(0)
+            .map(tz::timezone::LocalTimeType::ut_offset)
+            .map_err(::serde::ser::Error::custom)?;
+        serializer.serialize_i32(seconds)
+    }
+}
+
+impl<'de> Deserialize<'de> for Offset {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let seconds = i32::deserialize(deserializer)?;
+        Ok(Offset::fixed(seconds))
+    }
+}
+
+// `ToA` is a flat record of the broken-down calendar fields; it serializes as a
+// struct via the shadow type below so the representation stays stable regardless
+// of field order.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ToA")]
+struct ToARepr {
+    sec: u8,
+    min: u8,
+    hour: u8,
+    day: u8,
+    month: u8,
+    year: i32,
+    wday: u8,
+    yday: u16,
+    isdst: bool,
+    zone: Offset,
+}
+
+impl Serialize for ToA {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ToARepr {
+            sec: self.sec,
+            min: self.min,
+            hour: self.hour,
+            day: self.day,
+            month: self.month,
+            year: self.year,
+            wday: self.wday,
+            yday: self.yday,
+            isdst: self.isdst,
+            zone: self.zone,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ToA {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ToARepr {
+            sec,
+            min,
+            hour,
+            day,
+            month,
+            year,
+            wday,
+            yday,
+            isdst,
+            zone,
+        } = ToARepr::deserialize(deserializer)?;
+        Ok(ToA {
+            sec,
+            min,
+            hour,
+            day,
+            month,
+            year,
+            wday,
+            yday,
+            isdst,
+            zone,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_json() {
+        let time = Time::utc(1970, 1, 1, 0, 1, 0, 0);
+        let json = serde_json::to_string(&time).unwrap();
+        assert_eq!(json, "\"1970-01-01T00:01:00Z\"");
+        let parsed: Time = serde_json::from_str(&json).unwrap();
+        assert_eq!(time, parsed);
+    }
+
+    #[test]
+    fn timestamp_module_round_trips() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            #[serde(with = "super::timestamp")]
+            at: Time,
+        }
+
+        let wrapper = Wrapper {
+            at: Time::utc(1970, 1, 1, 0, 1, 0, 0),
+        };
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(json, "{\"at\":60}");
+        let parsed: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(wrapper.at, parsed.at);
+    }
+
+    #[test]
+    fn offset_round_trips_through_json() {
+        let offset = Offset::fixed(3600);
+        let json = serde_json::to_string(&offset).unwrap();
+        assert_eq!(json, "3600");
+        let parsed: Offset = serde_json::from_str(&json).unwrap();
+        assert_eq!(offset, parsed);
+    }
+
+    #[test]
+    fn to_a_round_trips_through_json() {
+        let to_a = Time::utc(1970, 1, 1, 0, 1, 0, 0).to_array();
+        let json = serde_json::to_string(&to_a).unwrap();
+        let parsed: ToA = serde_json::from_str(&json).unwrap();
+        assert_eq!(to_a, parsed);
+    }
+}
@@ -0,0 +1,432 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::Time;
+
+/// The set of date and time components reachable from a [`Time`].
+///
+/// Both [`strftime`](Time::strftime) and the RFC 2822 / RFC 3339 renderers
+/// pull the exact same fields out of the wrapped [`tz::datetime::DateTime`] and
+/// the stored [`Offset`], so the extraction lives here once and the formatters
+/// borrow it.
+///
+/// [`Offset`]: super::Offset
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Components<'a> {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanoseconds: u32,
+    /// Day of the week, `0` for Sunday through `6` for Saturday.
+    pub week_day: u8,
+    /// Day of the year, `0` for January 1st.
+    pub year_day: u16,
+    /// Offset east of UTC in seconds.
+    pub utc_offset: i32,
+    /// The time zone abbreviation, e.g. `UTC` or `EST`.
+    pub zone_designation: &'a str,
+    /// Seconds since the Unix epoch.
+    pub unix_time: i64,
+}
+
+impl<'a> Components<'a> {
+    /// Extract the broken-down components from a [`Time`].
+    pub(crate) fn from_time(time: &'a Time) -> Self {
+        let dt = &time.inner;
+        let local = dt.local_time_type();
+        Self {
+            year: dt.year(),
+            month: dt.month(),
+            day: dt.month_day(),
+            hour: dt.hour(),
+            minute: dt.minute(),
+            second: dt.second(),
+            nanoseconds: dt.nanoseconds(),
+            week_day: dt.week_day(),
+            year_day: dt.year_day(),
+            utc_offset: local.ut_offset(),
+            zone_designation: local.time_zone_designation(),
+            unix_time: dt.unix_time(),
+        }
+    }
+}
+
+/// Full English weekday names indexed by [`Components::week_day`].
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Abbreviated English weekday names indexed by [`Components::week_day`].
+const DAY_ABBREV: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Full English month names indexed by month number minus one.
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Abbreviated English month names indexed by month number minus one.
+const MONTH_ABBREV: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Padding to apply to a numeric conversion directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pad {
+    /// Zero-pad to the field width (the default for most numeric directives).
+    Zero,
+    /// Space-pad to the field width (the `_` flag).
+    Space,
+    /// Do not pad at all (the `-` flag).
+    None,
+}
+
+/// Returns `true` if the ISO 8601 calendar year `year` contains 53 weeks.
+fn is_long_year(year: i32) -> bool {
+    let p = |y: i32| (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)).rem_euclid(7);
+    p(year) == 4 || p(year - 1) == 3
+}
+
+/// Compute the ISO 8601 week-based year (`%G`) and week number (`%V`).
+fn iso_week(year: i32, year_day: u16, week_day: u8) -> (i32, u32) {
+    // ISO weekday, Monday is `1` through Sunday is `7`.
+    let iso_wday = if week_day == 0 { 7 } else { i32::from(week_day) };
+    // Ordinal day, `1` for January 1st.
+    let yday = i32::from(year_day) + 1;
+    let week = (yday - iso_wday + 10).div_euclid(7);
+    if week < 1 {
+        let prev = year - 1;
+        (prev, if is_long_year(prev) { 53 } else { 52 })
+    } else if week > 52 && !is_long_year(year) {
+        (year + 1, 1)
+    } else {
+        #[allow(clippy::cast_sign_loss)] // `week` is in `1..=53` in this branch
+        (year, week as u32)
+    }
+}
+
+impl fmt::Display for Time {
+    /// Formats a `Time` using the default Ruby `Time#to_s` format, which is
+    /// equivalent to the `strftime` directive string `%Y-%m-%d %H:%M:%S %z`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = Components::from_time(self);
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02} ",
+            c.year, c.month, c.day, c.hour, c.minute, c.second
+        )?;
+        let (sign, hh, mm) = offset_hms(c.utc_offset);
+        write!(f, "{sign}{hh:02}{mm:02}")
+    }
+}
+
+/// Split an offset in seconds into its sign and whole hour/minute magnitudes.
+fn offset_hms(offset: i32) -> (char, u32, u32) {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let abs = offset.unsigned_abs();
+    (sign, abs / 3600, (abs % 3600) / 60)
+}
+
+impl Time {
+    /// Formats _time_ according to the directives in the given format string.
+    ///
+    /// This implements the conversion directive set shared by MRI's
+    /// [`Time#strftime`] and chrono's `strftime` module: `%Y %C %y %m %d %e %H
+    /// %I %M %S %L %N %p %P %A %a %B %b %j %u %w %G %V %z %:z %Z %s %%`, along
+    /// with the `-`, `_`, and `0` padding flags and an optional field width.
+    ///
+    /// Literal bytes are copied through untouched and an unknown directive is
+    /// emitted verbatim (for example `%x` yields `"%x"`), so arbitrary
+    /// non-UTF-8 format strings round-trip losslessly. The result is returned
+    /// as a `Vec<u8>` to preserve those bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_time::tzrs::Time;
+    /// let t = Time::utc(1970, 1, 1, 0, 1, 0, 0);
+    /// assert_eq!(t.strftime(b"%Y-%m-%d"), b"1970-01-01".to_vec());
+    /// ```
+    ///
+    /// [`Time#strftime`]: https://ruby-doc.org/core-2.6.3/Time.html#method-i-strftime
+    #[must_use]
+    pub fn strftime(&self, fmt: &[u8]) -> Vec<u8> {
+        let c = Components::from_time(self);
+        let mut out = Vec::with_capacity(fmt.len());
+        let mut bytes = fmt.iter().copied().enumerate().peekable();
+
+        while let Some((start, byte)) = bytes.next() {
+            if byte != b'%' {
+                out.push(byte);
+                continue;
+            }
+
+            // Read optional flag, then optional width, then the directive
+            // letter. Anything that doesn't form a complete directive is copied
+            // through literally from the `%`.
+            let mut pad = None;
+            if let Some(&(_, flag @ (b'-' | b'_' | b'0'))) = bytes.peek() {
+                pad = Some(match flag {
+                    b'-' => Pad::None,
+                    b'_' => Pad::Space,
+                    _ => Pad::Zero,
+                });
+                bytes.next();
+            }
+
+            let mut width = 0_usize;
+            let mut had_width = false;
+            while let Some(&(_, digit @ b'0'..=b'9')) = bytes.peek() {
+                had_width = true;
+                width = width.saturating_mul(10).saturating_add(usize::from(digit - b'0'));
+                bytes.next();
+            }
+
+            match bytes.peek().map(|&(_, b)| b) {
+                Some(letter) => {
+                    bytes.next();
+                    // `%:z` spans two bytes after the `%`; consume the trailing
+                    // `z` so it is not re-emitted literally on the next pass.
+                    if letter == b':' && bytes.peek().map(|&(_, b)| b) == Some(b'z') {
+                        bytes.next();
+                    }
+                    format_directive(&mut out, &c, letter, pad, width, had_width, &fmt[start..]);
+                }
+                // Trailing `%` (possibly with flags/width); copy the raw bytes.
+                None => out.extend_from_slice(&fmt[start..]),
+            }
+        }
+
+        out
+    }
+}
+
+/// Emit a single conversion directive into `out`.
+///
+/// `raw` is the slice of the format string starting at the leading `%`, used to
+/// echo unknown directives verbatim.
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+fn format_directive(
+    out: &mut Vec<u8>,
+    c: &Components<'_>,
+    letter: u8,
+    pad: Option<Pad>,
+    width: usize,
+    had_width: bool,
+    raw: &[u8],
+) {
+    // Default padding and width for each numeric directive, overridden by an
+    // explicit flag or width in the format string.
+    let num = |out: &mut Vec<u8>, value: i64, default_pad: Pad, default_width: usize| {
+        let pad = pad.unwrap_or(default_pad);
+        let width = if had_width { width } else { default_width };
+        write_padded(out, value, pad, width);
+    };
+
+    match letter {
+        b'Y' => num(out, i64::from(c.year), Pad::Zero, 4),
+        b'C' => num(out, i64::from(c.year).div_euclid(100), Pad::Zero, 2),
+        b'y' => num(out, i64::from(c.year).rem_euclid(100), Pad::Zero, 2),
+        b'm' => num(out, i64::from(c.month), Pad::Zero, 2),
+        b'd' => num(out, i64::from(c.day), Pad::Zero, 2),
+        b'e' => num(out, i64::from(c.day), Pad::Space, 2),
+        b'H' => num(out, i64::from(c.hour), Pad::Zero, 2),
+        b'I' => {
+            let hour12 = match c.hour % 12 {
+                0 => 12,
+                h => h,
+            };
+            num(out, i64::from(hour12), Pad::Zero, 2);
+        }
+        b'M' => num(out, i64::from(c.minute), Pad::Zero, 2),
+        b'S' => num(out, i64::from(c.second), Pad::Zero, 2),
+        b'L' => num(out, i64::from(c.nanoseconds / 1_000_000), Pad::Zero, 3),
+        b'N' => {
+            // Sub-second digits, zero-padded to the requested width (default 9)
+            // and truncated when a smaller width is given.
+            let digits = if had_width { width } else { 9 };
+            write_subsec(out, c.nanoseconds, digits);
+        }
+        b'p' => out.extend_from_slice(if c.hour < 12 { b"AM" } else { b"PM" }),
+        b'P' => out.extend_from_slice(if c.hour < 12 { b"am" } else { b"pm" }),
+        b'A' => out.extend_from_slice(DAY_NAMES[usize::from(c.week_day)].as_bytes()),
+        b'a' => out.extend_from_slice(DAY_ABBREV[usize::from(c.week_day)].as_bytes()),
+        b'B' => out.extend_from_slice(MONTH_NAMES[usize::from(c.month - 1)].as_bytes()),
+        b'b' | b'h' => out.extend_from_slice(MONTH_ABBREV[usize::from(c.month - 1)].as_bytes()),
+        b'j' => num(out, i64::from(c.year_day) + 1, Pad::Zero, 3),
+        b'u' => {
+            let iso_wday = if c.week_day == 0 { 7 } else { c.week_day };
+            num(out, i64::from(iso_wday), Pad::None, 0);
+        }
+        b'w' => num(out, i64::from(c.week_day), Pad::None, 0),
+        b'G' => {
+            let (iso_year, _) = iso_week(c.year, c.year_day, c.week_day);
+            num(out, i64::from(iso_year), Pad::Zero, 4);
+        }
+        b'V' => {
+            let (_, week) = iso_week(c.year, c.year_day, c.week_day);
+            num(out, i64::from(week), Pad::Zero, 2);
+        }
+        b'z' => {
+            let (sign, hh, mm) = offset_hms(c.utc_offset);
+            out.push(sign as u8);
+            write_padded(out, i64::from(hh), Pad::Zero, 2);
+            write_padded(out, i64::from(mm), Pad::Zero, 2);
+        }
+        b'Z' => {
+            // The time zone abbreviation carried by the local time type,
+            // e.g. `UTC` or `EST`.
+            out.extend_from_slice(c.zone_designation.as_bytes());
+        }
+        b's' => num(out, c.unix_time, Pad::None, 0),
+        b':' => {
+            // `%:z` formats the offset as `±HH:MM`.
+            if raw.get(2) == Some(&b'z') {
+                let (sign, hh, mm) = offset_hms(c.utc_offset);
+                out.push(sign as u8);
+                write_padded(out, i64::from(hh), Pad::Zero, 2);
+                out.push(b':');
+                write_padded(out, i64::from(mm), Pad::Zero, 2);
+            } else {
+                out.extend_from_slice(&raw[..2]);
+            }
+        }
+        b'%' => out.push(b'%'),
+        // Unknown directive: echo the `%`, any flag and width, and the
+        // directive letter unchanged.
+        other => {
+            let mut len = 1;
+            if let Some(b'-' | b'_' | b'0') = raw.get(len) {
+                len += 1;
+            }
+            while let Some(b'0'..=b'9') = raw.get(len) {
+                len += 1;
+            }
+            debug_assert_eq!(raw.get(len), Some(&other));
+            out.extend_from_slice(&raw[..=len]);
+        }
+    }
+}
+
+/// Write `value` as a decimal integer padded to at least `width` columns.
+fn write_padded(out: &mut Vec<u8>, value: i64, pad: Pad, width: usize) {
+    let negative = value < 0;
+    let digits = value.unsigned_abs().to_string();
+    let sign_len = usize::from(negative);
+    let total = digits.len() + sign_len;
+
+    if pad != Pad::None && total < width {
+        let fill = if pad == Pad::Space { b' ' } else { b'0' };
+        if fill == b'0' && negative {
+            out.push(b'-');
+        }
+        out.extend(core::iter::repeat(fill).take(width - total));
+        if fill == b'0' {
+            out.extend_from_slice(digits.as_bytes());
+            return;
+        }
+    }
+    if negative {
+        out.push(b'-');
+    }
+    out.extend_from_slice(digits.as_bytes());
+}
+
+/// Write the sub-second field zero-padded to nine digits, then truncated or
+/// extended to `width` columns (`%N`).
+fn write_subsec(out: &mut Vec<u8>, nanoseconds: u32, width: usize) {
+    let nanos = format!("{nanoseconds:09}");
+    if width <= nanos.len() {
+        out.extend_from_slice(&nanos.as_bytes()[..width]);
+    } else {
+        out.extend_from_slice(nanos.as_bytes());
+        out.extend(core::iter::repeat(b'0').take(width - nanos.len()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc_epoch() -> Time {
+        Time::utc(1970, 1, 1, 0, 1, 0, 0)
+    }
+
+    #[test]
+    fn literal_bytes_pass_through() {
+        let t = utc_epoch();
+        assert_eq!(t.strftime(b"hello"), b"hello".to_vec());
+    }
+
+    #[test]
+    fn date_directives() {
+        let t = utc_epoch();
+        assert_eq!(t.strftime(b"%Y-%m-%d"), b"1970-01-01".to_vec());
+        assert_eq!(t.strftime(b"%H:%M:%S"), b"00:01:00".to_vec());
+    }
+
+    #[test]
+    fn padding_flags() {
+        let t = Time::utc(2022, 2, 3, 4, 5, 6, 0);
+        assert_eq!(t.strftime(b"%-m"), b"2".to_vec());
+        assert_eq!(t.strftime(b"%_m"), b" 2".to_vec());
+        assert_eq!(t.strftime(b"%3m"), b"002".to_vec());
+    }
+
+    #[test]
+    fn unknown_directive_is_literal() {
+        let t = utc_epoch();
+        assert_eq!(t.strftime(b"%q"), b"%q".to_vec());
+    }
+
+    #[test]
+    fn escaped_percent() {
+        let t = utc_epoch();
+        assert_eq!(t.strftime(b"100%%"), b"100%".to_vec());
+    }
+
+    #[test]
+    fn offset_directives() {
+        use super::super::Offset;
+
+        let t = Time::new(2022, 2, 3, 4, 5, 6, 0, Offset::fixed(3600));
+        assert_eq!(t.strftime(b"%z"), b"+0100".to_vec());
+        assert_eq!(t.strftime(b"%:z"), b"+01:00".to_vec());
+        // The trailing `z` of `%:z` is consumed, not re-emitted literally.
+        assert_eq!(t.strftime(b"%:z!"), b"+01:00!".to_vec());
+    }
+
+    #[test]
+    fn zone_designation() {
+        let t = utc_epoch();
+        assert_eq!(t.strftime(b"%Z"), b"UTC".to_vec());
+    }
+
+    #[test]
+    fn subsecond_width() {
+        let t = Time::utc(1970, 1, 1, 0, 0, 0, 123_456_789);
+        assert_eq!(t.strftime(b"%N"), b"123456789".to_vec());
+        assert_eq!(t.strftime(b"%3N"), b"123".to_vec());
+        assert_eq!(t.strftime(b"%L"), b"123".to_vec());
+    }
+}